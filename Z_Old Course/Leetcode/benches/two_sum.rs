@@ -0,0 +1,39 @@
+// HashMap two_sum vs sort-then-two-pointer, run with `cargo bench`.
+// The HashMap version is O(n) but allocates and hashes; the two-pointer one sorts an index Vec
+// and then walks it linearly, which is more cache-friendly but O(n log n).
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use leetcode::Solution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn input(size: usize, rng: &mut StdRng) -> (Vec<i32>, i32) {
+    let nums: Vec<i32> = (0..size).map(|_| rng.gen_range(-1_000_000..=1_000_000)).collect();
+    // Target is the sum of two random distinct positions, so a pair always exists
+    let i = rng.gen_range(0..size);
+    let j = (i + rng.gen_range(1..size)) % size;
+    let target = nums[i] + nums[j];
+    (nums, target)
+}
+
+fn bench_two_sum(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut group = c.benchmark_group("two_sum");
+    group.sample_size(10);
+
+    // Both take nums by value, the clone happens in iter_batched's untimed setup
+    for size in [100, 10_000, 1_000_000] {
+        let (nums, target) = input(size, &mut rng);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("hash_map", size), &nums, |b, nums| {
+            b.iter_batched(|| nums.clone(), |nums| Solution::two_sum(nums, target), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("two_pointers", size), &nums, |b, nums| {
+            b.iter_batched(|| nums.clone(), |nums| Solution::two_sum_two_pointers(nums, target), BatchSize::LargeInput)
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_two_sum);
+criterion_main!(benches);
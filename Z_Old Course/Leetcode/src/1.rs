@@ -1,17 +1,76 @@
 use std::collections::HashMap;
+use std::hash::Hash;
+
+use num_traits::PrimInt;
+
+use crate::Solution;
+
+// two_sum for any primitive integer, returns the indices (earlier first) or None if no pair adds up to target
+pub fn two_sum_generic<T: PrimInt + Hash>(nums: &[T], target: T) -> Option<[usize; 2]> {
+    // Create a hash map to store the difference between target and each number in nums
+    let mut hm = HashMap::with_capacity(nums.len());
+    for (i, &num) in nums.iter().enumerate() {
+        // Check if such a difference exists in the hash map
+        match hm.get(&num) {
+            // If it does, return the indices of the number with the difference and the current number (earlier index first)
+            Some(&j) => return Some([j, i]),
+            // If it doesn't, add the difference between target and the current number to the hash map.
+            // A difference that overflows T can't match any element of nums, so it's skipped instead of panicking.
+            None => {
+                if let Some(diff) = target.checked_sub(&num) {
+                    hm.insert(diff, i);
+                }
+            }
+        }
+    }
+    None
+}
 
 impl Solution {
     pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
-        // Create a hash map to store the difference between target and each number in nums
-        let mut hm = HashMap::with_capacity(nums.len());
+        let [j, i] = two_sum_generic(&nums, target).expect("no two numbers add up to target");
+        vec![j as i32, i as i32]
+    }
+
+    // two_sum stops at the pair whose second index comes first, e.g. [1, 4, 2, 5] with 6 gives [1, 2].
+    // This returns the first pair in nested-loop order instead (smallest i, then smallest j > i), so [0, 3].
+    pub fn two_sum_ordered(nums: Vec<i32>, target: i32) -> Vec<i32> {
+        // Every index of each value, in ascending order
+        let mut positions: HashMap<i32, Vec<usize>> = HashMap::with_capacity(nums.len());
+        for (i, &num) in nums.iter().enumerate() {
+            positions.entry(num).or_default().push(i);
+        }
         for (i, &num) in nums.iter().enumerate() {
-            // Check if such a difference exists in the hash map
-            match hm.get(&num) {
-                // If it does, return the indices of the current number and the number with the difference
-                Some(&j) => return vec![i as i32, j as i32],
-                // If it doesn't, add the difference between target and the current number to the hash map
-                None => {
-                    hm.insert(target - num, i);
+            // A complement that overflows i32 can't be in nums, skip it like two_sum_generic does
+            let Some(complement) = target.checked_sub(num) else {
+                continue;
+            };
+            if let Some(js) = positions.get(&complement) {
+                // First position of the complement after i
+                if let Some(&j) = js.get(js.partition_point(|&j| j <= i)) {
+                    return vec![i as i32, j as i32];
+                }
+            }
+        }
+        unreachable!();
+    }
+
+    // Sort-then-two-pointer variant, kept for comparison in benches/two_sum.rs.
+    // No hash map allocation, but O(n log n) and it has to sort indices to remember original positions.
+    pub fn two_sum_two_pointers(nums: Vec<i32>, target: i32) -> Vec<i32> {
+        // Checked up front, idx.len() - 1 below would underflow on an empty nums
+        assert!(nums.len() >= 2, "two_sum needs at least two numbers");
+        let mut idx: Vec<usize> = (0..nums.len()).collect();
+        idx.sort_unstable_by_key(|&i| nums[i]);
+        let (mut lo, mut hi) = (0, idx.len() - 1);
+        while lo < hi {
+            // Summed in i64 so two large i32s can't overflow
+            match (nums[idx[lo]] as i64 + nums[idx[hi]] as i64).cmp(&(target as i64)) {
+                std::cmp::Ordering::Less => lo += 1,
+                std::cmp::Ordering::Greater => hi -= 1,
+                std::cmp::Ordering::Equal => {
+                    let (a, b) = (idx[lo].min(idx[hi]), idx[lo].max(idx[hi]));
+                    return vec![a as i32, b as i32];
                 }
             }
         }
@@ -21,11 +80,53 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::two_sum;
+    use super::two_sum_generic;
+    use crate::Solution;
 
     #[test]
     fn test_two_sum() {
         // Standard case.
-        assert_eq!(two_sum(vec![1, 2, 3, 4, 5], 9), vec![3, 4]);
+        assert_eq!(Solution::two_sum(vec![1, 2, 3, 4, 5], 9), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_two_sum_generic_i8() {
+        // 100 - (-100) overflows i8 and has to be skipped.
+        assert_eq!(two_sum_generic(&[-100i8, 120, -20, 50], 100), Some([1, 2]));
+        // Every difference but the last overflows.
+        assert_eq!(two_sum_generic(&[1i8, 127, -128, 0], -128), Some([2, 3]));
+        assert_eq!(two_sum_generic(&[127i8, 127], -128), None);
+        // Wider types work too.
+        assert_eq!(two_sum_generic(&[u64::MAX, 1, 0], u64::MAX), Some([0, 2]));
+    }
+
+    #[test]
+    fn test_two_sum_ordered() {
+        // Standard case.
+        assert_eq!(Solution::two_sum_ordered(vec![3, 2, 4], 6), vec![1, 2]);
+        // Same value twice.
+        assert_eq!(Solution::two_sum_ordered(vec![3, 3], 6), vec![0, 1]);
+        // First pair by i, where two_sum returns the pair completed first.
+        assert_eq!(Solution::two_sum_ordered(vec![1, 4, 2, 5], 6), vec![0, 3]);
+        assert_eq!(Solution::two_sum(vec![1, 4, 2, 5], 6), vec![1, 2]);
+        // target - i32::MIN overflows and has to be skipped.
+        assert_eq!(Solution::two_sum_ordered(vec![i32::MIN, 0, 1], 1), vec![1, 2]);
+        assert_eq!(Solution::two_sum_ordered(vec![i32::MAX, i32::MIN, -1], -1), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_two_sum_two_pointers() {
+        // Standard case.
+        assert_eq!(Solution::two_sum_two_pointers(vec![1, 2, 3, 4, 5], 9), vec![3, 4]);
+        // Unsorted input keeps the original indices.
+        assert_eq!(Solution::two_sum_two_pointers(vec![3, 2, 4], 6), vec![1, 2]);
+        // Sum of the two largest would overflow i32.
+        assert_eq!(Solution::two_sum_two_pointers(vec![i32::MAX, -1, i32::MAX, 1], 0), vec![1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "two_sum needs at least two numbers")]
+    fn test_two_sum_two_pointers_empty() {
+        Solution::two_sum_two_pointers(vec![], 0);
     }
 }
\ No newline at end of file
@@ -14,9 +14,11 @@
 //     }
 //   }
 // }
+use crate::list_node::{to_vec, ListNode};
+use crate::Solution;
+
 impl Solution {
     pub fn add_two_numbers(l1: Option<Box<ListNode>>, l2: Option<Box<ListNode>>) -> Option<Box<ListNode>> {
-        let mut sum = 0;
         let mut carry = 0;
         let mut l3 = Some(Box::new(ListNode { val: 0, next: None }));
         let mut head = l3.as_mut();
@@ -24,7 +26,7 @@ impl Solution {
         let (mut l1, mut l2) = (l1.as_ref(), l2.as_ref());
 
         while l1.is_some() || l2.is_some() {
-            sum = 0;
+            let mut sum = 0;
 
             if let Some(node) = l1 {
                 sum += node.val;
@@ -48,4 +50,106 @@ impl Solution {
 
         l3.unwrap().next
     }
-}
\ No newline at end of file
+
+    // add_two_numbers assumes every node is a digit; this checks first instead of producing garbage
+    pub fn try_add_two_numbers(l1: Option<Box<ListNode>>, l2: Option<Box<ListNode>>) -> Result<Option<Box<ListNode>>, DigitError> {
+        check_digits(&l1)?;
+        check_digits(&l2)?;
+        Ok(Self::add_two_numbers(l1, l2))
+    }
+}
+
+// A node outside 0..=9. l1 is checked before l2, and node_index is the 0-based position within that list.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DigitError {
+    pub node_index: usize,
+    pub value: i32,
+}
+
+fn check_digits(head: &Option<Box<ListNode>>) -> Result<(), DigitError> {
+    let mut cur = head.as_ref();
+    let mut node_index = 0;
+    while let Some(node) = cur {
+        if !(0..=9).contains(&node.val) {
+            return Err(DigitError { node_index, value: node.val });
+        }
+        node_index += 1;
+        cur = node.next.as_ref();
+    }
+    Ok(())
+}
+
+// Native-arithmetic cross-check for add_two_numbers: both lists are least significant digit first.
+// None if a node is negative or the sum doesn't fit in u128 (about 38 digits, LeetCode allows lists of up to 100).
+pub fn sum_as_number(l1: &Option<Box<ListNode>>, l2: &Option<Box<ListNode>>) -> Option<u128> {
+    fn to_number(head: &Option<Box<ListNode>>) -> Option<u128> {
+        // Most significant digit first, so each step is n * 10 + digit
+        to_vec(head).into_iter().rev().try_fold(0u128, |n, val| {
+            let digit = u128::try_from(val).ok()?;
+            n.checked_mul(10)?.checked_add(digit)
+        })
+    }
+    to_number(l1)?.checked_add(to_number(l2)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sum_as_number, DigitError};
+    use crate::list_node::{list_from_number, to_vec, ListNode};
+    use crate::Solution;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn digits(n: u128) -> Vec<i32> {
+        n.to_string().bytes().rev().map(|b| (b - b'0') as i32).collect()
+    }
+
+    fn check(a: u64, b: u64) {
+        let (l1, l2) = (list_from_number(a, true), list_from_number(b, true));
+        let expected = sum_as_number(&l1, &l2).unwrap();
+        assert_eq!(expected, a as u128 + b as u128);
+        assert_eq!(to_vec(&Solution::add_two_numbers(l1, l2)), digits(expected), "{a} + {b}");
+    }
+
+    #[test]
+    fn test_add_two_numbers() {
+        // Standard case, 342 + 465 = 807.
+        check(342, 465);
+        // Carry into a new digit.
+        check(999, 1);
+        check(0, 0);
+    }
+
+    #[test]
+    fn test_sum_as_number_out_of_range() {
+        // 38 nines fit, 40 don't.
+        let nines = |n: usize| ListNode::from_vec(vec![9; n]);
+        assert_eq!(sum_as_number(&nines(38), &None), Some(10u128.pow(38) - 1));
+        assert_eq!(sum_as_number(&nines(40), &None), None);
+        // u128::MAX + 1.
+        let max = ListNode::from_vec(digits(u128::MAX));
+        assert_eq!(sum_as_number(&max, &None), Some(u128::MAX));
+        assert_eq!(sum_as_number(&max, &ListNode::from_vec(vec![1])), None);
+        // Negative digit.
+        assert_eq!(sum_as_number(&ListNode::from_vec(vec![-1]), &None), None);
+    }
+
+    #[test]
+    fn test_add_two_numbers_matches_native() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..1000 {
+            check(rng.gen_range(0..1_000_000_000), rng.gen_range(0..1_000_000_000));
+        }
+    }
+
+    #[test]
+    fn test_try_add_two_numbers() {
+        let sum = Solution::try_add_two_numbers(ListNode::from_vec(vec![2, 4, 3]), ListNode::from_vec(vec![5, 6, 4]));
+        assert_eq!(to_vec(&sum.unwrap()), vec![7, 0, 8]);
+        // Not a digit.
+        let sum = Solution::try_add_two_numbers(ListNode::from_vec(vec![2, 4]), ListNode::from_vec(vec![5, 6, 10]));
+        assert_eq!(sum, Err(DigitError { node_index: 2, value: 10 }));
+        let sum = Solution::try_add_two_numbers(ListNode::from_vec(vec![-1]), None);
+        assert_eq!(sum, Err(DigitError { node_index: 0, value: -1 }));
+    }
+}
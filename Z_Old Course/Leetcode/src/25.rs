@@ -0,0 +1,66 @@
+use crate::list_node::ListNode;
+use crate::Solution;
+
+impl Solution {
+    pub fn reverse_k_group(head: Option<Box<ListNode>>, k: i32) -> Option<Box<ListNode>> {
+        let k = k.max(1) as usize;
+        let mut dummy = Box::new(ListNode::new(0));
+        let mut tail = &mut dummy;
+        let mut rest = head;
+
+        // Only full groups are reversed, a shorter trailing group keeps its order
+        while has_k_nodes(&rest, k) {
+            let (group, next) = reverse_k_nodes(rest, k);
+            tail.next = group;
+            for _ in 0..k {
+                tail = tail.next.as_mut().unwrap();
+            }
+            rest = next;
+        }
+
+        tail.next = rest;
+        dummy.next
+    }
+}
+
+fn has_k_nodes(head: &Option<Box<ListNode>>, k: usize) -> bool {
+    let mut cur = head.as_ref();
+    for _ in 0..k {
+        match cur {
+            Some(node) => cur = node.next.as_ref(),
+            None => return false,
+        }
+    }
+    true
+}
+
+// Reverses the first k nodes by relinking them, returns (reversed group, rest of the list)
+fn reverse_k_nodes(mut head: Option<Box<ListNode>>, k: usize) -> (Option<Box<ListNode>>, Option<Box<ListNode>>) {
+    let mut prev = None;
+    for _ in 0..k {
+        match head {
+            Some(mut node) => {
+                head = node.next.take();
+                node.next = prev;
+                prev = Some(node);
+            }
+            None => break,
+        }
+    }
+    (prev, head)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list_node::{to_vec, ListNode};
+    use crate::Solution;
+
+    #[test]
+    fn test_reverse_k_group() {
+        // Trailing group shorter than k stays in order.
+        let head = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(to_vec(&Solution::reverse_k_group(head, 2)), vec![2, 1, 4, 3, 5]);
+        let head = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(to_vec(&Solution::reverse_k_group(head, 3)), vec![3, 2, 1, 4, 5]);
+    }
+}
@@ -0,0 +1,41 @@
+use std::ops::Add;
+
+// F(0) = 0, F(1) = F(2) = 1, computed iteratively for any T that can add, so u128 or a bignum go past u64
+pub fn fibonacci_generic<T>(n: u64) -> T
+where
+    T: Clone + Add<Output = T> + From<u8>,
+{
+    if n == 0 {
+        return T::from(0u8);
+    }
+    // (F(i - 1), F(i)), starting at i = 2. Stops at F(n) so no term past it is computed (and overflows).
+    let (mut a, mut b) = (T::from(1u8), T::from(1u8));
+    for _ in 2..n {
+        let next = a + b.clone();
+        a = b;
+        b = next;
+    }
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fibonacci_generic;
+
+    #[test]
+    fn test_fibonacci_generic() {
+        let first: Vec<u64> = (0..10).map(fibonacci_generic).collect();
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        // F(93) is the largest that fits in u64.
+        assert_eq!(fibonacci_generic::<u64>(93), 12_200_160_415_121_876_738);
+    }
+
+    #[test]
+    fn test_fibonacci_generic_u128() {
+        // Past u64::MAX.
+        let f94 = fibonacci_generic::<u128>(94);
+        assert!(f94 > u64::MAX as u128);
+        assert_eq!(f94, 19_740_274_219_868_223_167);
+        assert_eq!(fibonacci_generic::<u128>(100), 354_224_848_179_261_915_075);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::list_node::ListNode;
+use crate::Solution;
+
+impl Solution {
+    // LeetCode calls this one delete_duplicates too, renamed so it can live next to 83 on Solution
+    pub fn delete_all_duplicates(head: Option<Box<ListNode>>) -> Option<Box<ListNode>> {
+        // Dummy head, so a run of duplicates at the front is handled like any other
+        let mut dummy = Box::new(ListNode { val: 0, next: None });
+        let mut tail = &mut dummy;
+        let mut cur = head;
+
+        while let Some(mut node) = cur {
+            cur = node.next.take();
+            if cur.as_ref().is_some_and(|next| next.val == node.val) {
+                // Drop the whole run of this value, including `node` itself
+                while cur.as_ref().is_some_and(|next| next.val == node.val) {
+                    cur = cur.and_then(|next| next.next);
+                }
+            } else {
+                tail.next = Some(node);
+                tail = tail.next.as_mut().unwrap();
+            }
+        }
+
+        dummy.next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list_node::{to_vec, ListNode};
+    use crate::Solution;
+
+    #[test]
+    fn test_delete_all_duplicates() {
+        // Standard case.
+        let head = ListNode::from_vec(vec![1, 1, 2, 3, 3]);
+        assert_eq!(to_vec(&Solution::delete_all_duplicates(head)), vec![2]);
+        // Every value repeated.
+        let head = ListNode::from_vec(vec![1, 1, 1]);
+        assert_eq!(Solution::delete_all_duplicates(head), None);
+    }
+}
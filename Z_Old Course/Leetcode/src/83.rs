@@ -0,0 +1,31 @@
+use crate::list_node::ListNode;
+use crate::Solution;
+
+impl Solution {
+    pub fn delete_duplicates(mut head: Option<Box<ListNode>>) -> Option<Box<ListNode>> {
+        let mut cur = head.as_mut();
+        while let Some(node) = cur {
+            // The list is sorted, so duplicates are always adjacent: unlink them until the next value differs
+            while node.next.as_ref().is_some_and(|next| next.val == node.val) {
+                node.next = node.next.take().and_then(|next| next.next);
+            }
+            cur = node.next.as_mut();
+        }
+        head
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list_node::{to_vec, ListNode};
+    use crate::Solution;
+
+    #[test]
+    fn test_delete_duplicates() {
+        // Standard case.
+        let head = ListNode::from_vec(vec![1, 1, 2, 3, 3]);
+        assert_eq!(to_vec(&Solution::delete_duplicates(head)), vec![1, 2, 3]);
+        // Empty list.
+        assert_eq!(Solution::delete_duplicates(None), None);
+    }
+}
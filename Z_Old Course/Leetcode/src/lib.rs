@@ -0,0 +1,19 @@
+pub mod list_arena;
+pub mod list_node;
+#[cfg(feature = "serde")]
+pub mod list_serde;
+
+pub struct Solution;
+
+#[path = "1.rs"]
+pub mod two_sum;
+#[path = "2.rs"]
+pub mod add_two_numbers;
+#[path = "25.rs"]
+mod reverse_nodes_in_k_group;
+#[path = "82.rs"]
+mod remove_duplicates_from_sorted_list_ii;
+#[path = "83.rs"]
+mod remove_duplicates_from_sorted_list;
+#[path = "509.rs"]
+pub mod fibonacci_number;
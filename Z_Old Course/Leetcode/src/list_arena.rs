@@ -0,0 +1,98 @@
+// Box<ListNode> can't point back at an earlier node, so cycle problems (e.g. 142) use indices into a Vec instead.
+// The head is always the first pushed node, index 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaNode {
+    pub val: i32,
+    pub next: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct ListArena {
+    pub nodes: Vec<ArenaNode>,
+}
+
+impl ListArena {
+    pub fn new() -> Self {
+        ListArena::default()
+    }
+
+    // Adds an unlinked node and returns its index
+    pub fn push(&mut self, val: i32) -> usize {
+        self.nodes.push(ArenaNode { val, next: None });
+        self.nodes.len() - 1
+    }
+
+    // Points `from` at `to`, linking back to an earlier node creates a cycle.
+    // Panics here on a bad `to` rather than later inside detect_cycle.
+    pub fn link(&mut self, from: usize, to: usize) {
+        assert!(to < self.nodes.len(), "link target {to} out of range for {} nodes", self.nodes.len());
+        self.nodes[from].next = Some(to);
+    }
+
+    // Floyd's tortoise and hare, returns the index where the cycle starts
+    pub fn detect_cycle(&self) -> Option<usize> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let next = |i: usize| self.nodes[i].next;
+
+        // Phase 1: fast moves two steps per slow step, they only meet inside a cycle
+        let (mut slow, mut fast) = (0, 0);
+        loop {
+            slow = next(slow)?;
+            fast = next(next(fast)?)?;
+            if slow == fast {
+                break;
+            }
+        }
+
+        // Phase 2: the head and the meeting point are the same distance from the cycle entry
+        let mut from_head = 0;
+        while from_head != slow {
+            from_head = next(from_head)?;
+            slow = next(slow)?;
+        }
+        Some(from_head)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListArena;
+
+    fn chain(vals: &[i32]) -> ListArena {
+        let mut arena = ListArena::new();
+        for &val in vals {
+            let i = arena.push(val);
+            if i > 0 {
+                arena.link(i - 1, i);
+            }
+        }
+        arena
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        // 3 -> 2 -> 0 -> -4 -> back to 2.
+        let mut arena = chain(&[3, 2, 0, -4]);
+        arena.link(3, 1);
+        assert_eq!(arena.detect_cycle(), Some(1));
+        // Cycle back to the head itself.
+        let mut arena = chain(&[1, 2]);
+        arena.link(1, 0);
+        assert_eq!(arena.detect_cycle(), Some(0));
+    }
+
+    #[test]
+    fn test_detect_cycle_acyclic() {
+        assert_eq!(chain(&[1, 2, 3]).detect_cycle(), None);
+        assert_eq!(ListArena::new().detect_cycle(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "link target 5 out of range")]
+    fn test_link_out_of_range() {
+        let mut arena = chain(&[1, 2]);
+        arena.link(1, 5);
+    }
+}
@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+// Definition for singly-linked list, as given by LeetCode.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+
+    // Builds a list front to back, so v[0] becomes the head.
+    pub fn from_vec(v: Vec<i32>) -> Option<Box<ListNode>> {
+        let mut head = None;
+        for val in v.into_iter().rev() {
+            head = Some(Box::new(ListNode { val, next: head }));
+        }
+        head
+    }
+
+    // The last element becomes the head, so a most-significant-first number like [1, 2, 3]
+    // turns into the least-significant-first digits 3 -> 2 -> 1 that 2.rs expects
+    pub fn from_slice_reversed(v: &[i32]) -> Option<Box<ListNode>> {
+        let mut head = None;
+        for &val in v {
+            head = Some(Box::new(ListNode { val, next: head }));
+        }
+        head
+    }
+}
+
+// Arrow notation, "1 -> 2 -> 3 -> None". Debug keeps the derived nested form.
+impl fmt::Display for ListNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cur = Some(self);
+        while let Some(node) = cur {
+            write!(f, "{} -> ", node.val)?;
+            cur = node.next.as_deref();
+        }
+        write!(f, "None")
+    }
+}
+
+// Display for a head, which can't implement it directly (Option is a foreign type)
+pub fn fmt_list(head: &Option<Box<ListNode>>) -> String {
+    match head {
+        Some(node) => node.to_string(),
+        None => String::from("None"),
+    }
+}
+
+// Orders list heads by their first value so they can go into a BinaryHeap (e.g. for merge_k_lists).
+// BinaryHeap is a max-heap, wrap in std::cmp::Reverse to pop the smallest head first.
+#[derive(Debug)]
+pub struct HeapNode(pub Box<ListNode>);
+
+impl From<Box<ListNode>> for HeapNode {
+    fn from(node: Box<ListNode>) -> Self {
+        HeapNode(node)
+    }
+}
+
+// Equality has to agree with Ord, so only the head value is compared here too
+impl PartialEq for HeapNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.val == other.0.val
+    }
+}
+
+impl Eq for HeapNode {}
+
+impl PartialOrd for HeapNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.val.cmp(&other.0.val)
+    }
+}
+
+pub fn to_vec(head: &Option<Box<ListNode>>) -> Vec<i32> {
+    let mut v = Vec::new();
+    let mut cur = head.as_ref();
+    while let Some(node) = cur {
+        v.push(node.val);
+        cur = node.next.as_ref();
+    }
+    v
+}
+
+// Same result as `a == b` with the derived PartialEq, but walks the lists in a loop instead of recursing per node
+pub fn lists_equal(a: &Option<Box<ListNode>>, b: &Option<Box<ListNode>>) -> bool {
+    let (mut a, mut b) = (a.as_deref(), b.as_deref());
+    loop {
+        match (a, b) {
+            (None, None) => return true,
+            (Some(x), Some(y)) if x.val == y.val => {
+                a = x.next.as_deref();
+                b = y.next.as_deref();
+            }
+            _ => return false,
+        }
+    }
+}
+
+pub fn list_len(head: &Option<Box<ListNode>>) -> usize {
+    let mut len = 0;
+    let mut cur = head.as_ref();
+    while let Some(node) = cur {
+        len += 1;
+        cur = node.next.as_ref();
+    }
+    len
+}
+
+// Fast/slow pointers: fast moves two nodes per step, so slow is halfway when fast runs out.
+// For even lengths this is the second of the two middle nodes.
+pub fn middle_node(head: &Option<Box<ListNode>>) -> Option<&ListNode> {
+    let mut slow = head.as_deref();
+    let mut fast = head.as_deref();
+    while let Some(next) = fast.and_then(|node| node.next.as_deref()) {
+        slow = slow.and_then(|node| node.next.as_deref());
+        fast = next.next.as_deref();
+    }
+    slow
+}
+
+// Ascending, duplicates allowed (the order the sorted-list problems assume)
+pub fn is_sorted(head: &Option<Box<ListNode>>) -> bool {
+    let mut cur = head.as_ref();
+    while let Some(node) = cur {
+        if node.next.as_ref().is_some_and(|next| next.val < node.val) {
+            return false;
+        }
+        cur = node.next.as_ref();
+    }
+    true
+}
+
+// Inserts before the first larger-or-equal value, keeping an ascending list ascending
+pub fn insert_sorted(mut head: Option<Box<ListNode>>, val: i32) -> Option<Box<ListNode>> {
+    let mut cur = &mut head;
+    while cur.as_ref().is_some_and(|node| node.val < val) {
+        cur = &mut cur.as_mut().unwrap().next;
+    }
+    let next = cur.take();
+    *cur = Some(Box::new(ListNode { val, next }));
+    head
+}
+
+// Digit list for `n`, e.g. 123 -> 3 -> 2 -> 1 when least significant first (the order 2.rs expects).
+// 0 is a single 0 node rather than an empty list.
+pub fn list_from_number(mut n: u64, least_significant_first: bool) -> Option<Box<ListNode>> {
+    let mut digits = Vec::new();
+    loop {
+        digits.push((n % 10) as i32);
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if !least_significant_first {
+        digits.reverse();
+    }
+    ListNode::from_vec(digits)
+}
+
+// None if a node is negative or the number doesn't fit in u64 (about 20 digits), same as sum_as_number in 2.rs
+pub fn number_from_list(head: &Option<Box<ListNode>>, least_significant_first: bool) -> Option<u64> {
+    let mut digits = to_vec(head);
+    if least_significant_first {
+        digits.reverse();
+    }
+    digits.into_iter().try_fold(0u64, |n, d| n.checked_mul(10)?.checked_add(u64::try_from(d).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vec_to_vec() {
+        assert_eq!(to_vec(&ListNode::from_vec(vec![1, 2, 3])), vec![1, 2, 3]);
+        // Empty list.
+        assert_eq!(ListNode::from_vec(vec![]), None);
+    }
+
+    #[test]
+    fn test_from_slice_reversed() {
+        assert_eq!(to_vec(&ListNode::from_slice_reversed(&[1, 2, 3])), vec![3, 2, 1]);
+        assert_eq!(ListNode::from_slice_reversed(&[]), None);
+    }
+
+    #[test]
+    fn test_number_round_trip() {
+        assert_eq!(to_vec(&list_from_number(1000, true)), vec![0, 0, 0, 1]);
+        assert_eq!(to_vec(&list_from_number(1000, false)), vec![1, 0, 0, 0]);
+        for n in [0, 1000, 40_302_001] {
+            assert_eq!(number_from_list(&list_from_number(n, true), true), Some(n));
+            assert_eq!(number_from_list(&list_from_number(n, false), false), Some(n));
+        }
+        assert_eq!(number_from_list(&list_from_number(u64::MAX, true), true), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_number_from_list_out_of_range() {
+        // Negative digit.
+        assert_eq!(number_from_list(&ListNode::from_vec(vec![-1]), true), None);
+        // 21 digits don't fit in u64.
+        assert_eq!(number_from_list(&ListNode::from_vec(vec![1; 21]), false), None);
+        // Empty list.
+        assert_eq!(number_from_list(&None, true), Some(0));
+    }
+
+    #[test]
+    fn test_middle_node_and_len() {
+        // Odd length.
+        let head = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(list_len(&head), 5);
+        assert_eq!(middle_node(&head).map(|node| node.val), Some(3));
+        // Even length returns the second middle.
+        let head = ListNode::from_vec(vec![1, 2, 3, 4]);
+        assert_eq!(list_len(&head), 4);
+        assert_eq!(middle_node(&head).map(|node| node.val), Some(3));
+        // Empty list.
+        assert_eq!(list_len(&None), 0);
+        assert!(middle_node(&None).is_none());
+    }
+
+    #[test]
+    fn test_heap_node_min_heap() {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let mut heap = BinaryHeap::new();
+        for val in [5, 1, 4, 2, 3] {
+            heap.push(Reverse(HeapNode::from(Box::new(ListNode::new(val)))));
+        }
+        let popped: Vec<i32> = std::iter::from_fn(|| heap.pop()).map(|Reverse(node)| node.0.val).collect();
+        assert_eq!(popped, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_display() {
+        let head = ListNode::from_vec(vec![1, 2, 3]);
+        assert_eq!(head.as_ref().unwrap().to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(fmt_list(&head), "1 -> 2 -> 3 -> None");
+        assert_eq!(fmt_list(&None), "None");
+        // Debug is unchanged.
+        assert_eq!(format!("{:?}", ListNode::new(1)), "ListNode { val: 1, next: None }");
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        // Empty list.
+        let head = insert_sorted(None, 3);
+        assert_eq!(to_vec(&head), vec![3]);
+        assert!(is_sorted(&head));
+        // Front.
+        let head = insert_sorted(head, 1);
+        assert_eq!(to_vec(&head), vec![1, 3]);
+        assert!(is_sorted(&head));
+        // End.
+        let head = insert_sorted(head, 5);
+        assert_eq!(to_vec(&head), vec![1, 3, 5]);
+        assert!(is_sorted(&head));
+        // Middle.
+        let head = insert_sorted(head, 4);
+        assert_eq!(to_vec(&head), vec![1, 3, 4, 5]);
+        assert!(is_sorted(&head));
+
+        assert!(!is_sorted(&ListNode::from_vec(vec![1, 3, 2])));
+        assert!(is_sorted(&ListNode::from_vec(vec![1, 1, 2])));
+    }
+
+    #[test]
+    fn test_lists_equal() {
+        let a = ListNode::from_vec(vec![1, 2, 3]);
+        assert!(lists_equal(&a, &ListNode::from_vec(vec![1, 2, 3])));
+        assert!(lists_equal(&None, &None));
+        // Different length.
+        assert!(!lists_equal(&a, &ListNode::from_vec(vec![1, 2])));
+        assert!(!lists_equal(&a, &None));
+        // Different value.
+        assert!(!lists_equal(&a, &ListNode::from_vec(vec![1, 2, 4])));
+    }
+}
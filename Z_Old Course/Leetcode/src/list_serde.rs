@@ -0,0 +1,63 @@
+// JSON-array form for list heads, so fixtures can be written as [1,2,3].
+// Option<Box<ListNode>> is a foreign type, so the empty list is handled here for use with
+// #[serde(with = "leetcode::list_serde")]. A bare ListNode (always non-empty) also implements the traits.
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::list_node::ListNode;
+
+pub fn serialize<S: Serializer>(head: &Option<Box<ListNode>>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(std::iter::successors(head.as_deref(), |node| node.next.as_deref()).map(|node| node.val))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Box<ListNode>>, D::Error> {
+    Vec::<i32>::deserialize(deserializer).map(ListNode::from_vec)
+}
+
+impl Serialize for ListNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(std::iter::successors(Some(self), |node| node.next.as_deref()).map(|node| node.val))
+    }
+}
+
+impl<'de> Deserialize<'de> for ListNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserialize(deserializer)? {
+            Some(head) => Ok(*head),
+            None => Err(D::Error::invalid_length(0, &"a non-empty list")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list_node::{to_vec, ListNode};
+
+    fn to_json(head: &Option<Box<ListNode>>) -> String {
+        let mut buf = Vec::new();
+        super::serialize(head, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn from_json(json: &str) -> Option<Box<ListNode>> {
+        super::deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let head = ListNode::from_vec(vec![1, 2, 3]);
+        let json = to_json(&head);
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(to_vec(&from_json(&json)), to_vec(&head));
+        // Empty list.
+        assert_eq!(to_json(&None), "[]");
+        assert_eq!(from_json("[]"), None);
+    }
+
+    #[test]
+    fn test_list_node_impls() {
+        let node: ListNode = serde_json::from_str("[4,5]").unwrap();
+        assert_eq!(serde_json::to_string(&node).unwrap(), "[4,5]");
+        assert!(serde_json::from_str::<ListNode>("[]").is_err());
+    }
+}
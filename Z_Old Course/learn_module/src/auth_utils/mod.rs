@@ -1,6 +1,10 @@
+// cred is skipped so the password never ends up in a span, only the username is recorded
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(cred), fields(username = cred.username())))]
 pub(crate) fn login(cred: models::Credentials) {
     // authenticate user
     crate::database::get_user();
+    #[cfg(feature = "tracing")]
+    tracing::info!("login succeeded");
 }
 
 fn logout() {
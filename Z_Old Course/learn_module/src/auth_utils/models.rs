@@ -1,4 +1,66 @@
 pub struct Credentials {
     username: String,
     password: String
-}
\ No newline at end of file
+}
+
+impl Credentials {
+    // named setters instead of Credentials::new(username, password), so the two Strings can't be swapped by accident
+    pub fn builder() -> CredentialsBuilder {
+        CredentialsBuilder::default()
+    }
+
+    pub(crate) fn username(&self) -> &str {
+        &self.username
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BuilderError {
+    MissingUsername,
+    MissingPassword,
+}
+
+#[derive(Default)]
+pub struct CredentialsBuilder {
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl CredentialsBuilder {
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    // an empty string counts as unset
+    pub fn build(self) -> Result<Credentials, BuilderError> {
+        let username = self.username.filter(|u| !u.is_empty()).ok_or(BuilderError::MissingUsername)?;
+        let password = self.password.filter(|p| !p.is_empty()).ok_or(BuilderError::MissingPassword)?;
+        Ok(Credentials { username, password })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_complete() {
+        let cred = Credentials::builder().username("alice").password(String::from("secret")).build().unwrap();
+        assert_eq!(cred.username, "alice");
+        assert_eq!(cred.password, "secret");
+    }
+
+    #[test]
+    fn test_builder_missing_fields() {
+        assert_eq!(Credentials::builder().password("secret").build().err(), Some(BuilderError::MissingUsername));
+        assert_eq!(Credentials::builder().username("alice").build().err(), Some(BuilderError::MissingPassword));
+        assert_eq!(Credentials::builder().username("").password("secret").build().err(), Some(BuilderError::MissingUsername));
+        assert_eq!(Credentials::builder().username("alice").password("").build().err(), Some(BuilderError::MissingPassword));
+    }
+}
@@ -3,10 +3,20 @@ pub(crate) enum Status {
     Interrupted,
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument)]
 pub(crate) fn connect_to_database() -> Status {
-    return Status::Connected;
+    let status = Status::Connected;
+    #[cfg(feature = "tracing")]
+    match status {
+        Status::Connected => tracing::info!("database connected"),
+        Status::Interrupted => tracing::warn!("database connection interrupted"),
+    }
+    status
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument)]
 pub fn get_user() {
     // get user from database
+    #[cfg(feature = "tracing")]
+    tracing::debug!("fetching user");
 }
\ No newline at end of file
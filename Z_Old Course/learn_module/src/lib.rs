@@ -12,8 +12,29 @@ use database::Status;
 
 
 pub fn authenticated(cred: Credentials)  { // auth_util::models::Credentials
-    if Status::Connected = database::connect_to_database() { // database::connect_to_database
+    if let Status::Connected = database::connect_to_database() { // database::connect_to_database
          auth_utils::login(cred);
+    } else {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("login skipped, database not connected");
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[test]
+    fn test_login_spans() {
+        let cred = Credentials::builder().username("alice").password("secret").build().unwrap();
+        authenticated(cred);
+
+        assert!(logs_contain("connect_to_database: learn_module::database: database connected"));
+        assert!(logs_contain("login{username=\"alice\"}: learn_module::auth_utils: login succeeded"));
+        assert!(logs_contain("login{username=\"alice\"}:get_user: learn_module::database: fetching user"));
+        assert!(!logs_contain("secret"));
     }
 }
 
@@ -1,3 +1,4 @@
+#![allow(dead_code, unused_variables)]
 struct User {
     active: bool,
     username: String,
@@ -18,6 +19,9 @@ struct AlwaysEqual; //Trait
 
 #[derive(Debug)] //Rust does include functionality to print out debugging information, but we have to explicitly opt in to make that functionality available for our struct.
 struct Rectangle {
+    // top-left corner, y grows downwards
+    x: u32,
+    y: u32,
     width: u32,
     height: u32,
 }
@@ -27,14 +31,58 @@ struct Rectangle {
 // their first parameter is always self, which represents the instance of the struct the method is being called on.
 // Each struct is allowed to have multiple impl blocks.
 impl Rectangle {
+    // u32 * u32 can overflow: it panics in debug builds and silently wraps in release builds. Prefer checked_area for large dimensions.
     fn area(&self) -> u32 {
         self.width * self.height
     }
+
+    // Widened to u64 first, where the product of two u32s always fits
+    fn checked_area(&self) -> Option<u64> {
+        (self.width as u64).checked_mul(self.height as u64)
+    }
 }
 impl Rectangle {
     fn can_hold(&self, other: &Rectangle) -> bool {
         self.width > other.width && self.height > other.height
     }
+
+    // Dimensions saturate at u32::MAX instead of overflowing like a plain u32 multiply (see area)
+    fn scale(&self, factor: u32) -> Rectangle {
+        Rectangle {
+            x: self.x,
+            y: self.y,
+            width: self.width.saturating_mul(factor),
+            height: self.height.saturating_mul(factor),
+        }
+    }
+
+    // width / height, a zero height gives f64::INFINITY instead of NaN for a 0x0 rectangle
+    fn aspect_ratio(&self) -> f64 {
+        if self.height == 0 {
+            return f64::INFINITY;
+        }
+        self.width as f64 / self.height as f64
+    }
+
+    // Edges are inclusive, so a point on the border is inside.
+    // Right/bottom edges are computed in u64 so x + width can't overflow.
+    fn contains_point(&self, x: u32, y: u32) -> bool {
+        let (x, y) = (x as u64, y as u64);
+        let (left, top) = (self.x as u64, self.y as u64);
+        left <= x && x <= left + self.width as u64 && top <= y && y <= top + self.height as u64
+    }
+
+    // Area of the intersection, rectangles that only share an edge overlap by 0.
+    // Multiplied in u64 and capped at u32::MAX, so a large overlap can't overflow like area does.
+    fn overlap_area(&self, other: &Rectangle) -> u32 {
+        let overlap = |a: u32, a_len: u32, b: u32, b_len: u32| {
+            let start = a.max(b) as u64;
+            let end = (a as u64 + a_len as u64).min(b as u64 + b_len as u64);
+            end.saturating_sub(start)
+        };
+        let area = overlap(self.x, self.width, other.x, other.width) * overlap(self.y, self.height, other.y, other.height);
+        u32::try_from(area).unwrap_or(u32::MAX)
+    }
 }
 
 
@@ -47,6 +95,8 @@ impl Rectangle {
 impl Rectangle {
     fn square(size: u32) -> Self {
         Self {
+            x: 0,
+            y: 0,
             width: size,
             height: size,
         }
@@ -82,4 +132,65 @@ fn build_user(email: String, username: String) -> User {
         email,
         sign_in_count: 1,
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_area() {
+        let rect = Rectangle { x: 0, y: 0, width: 30, height: 50 };
+        assert_eq!(rect.checked_area(), Some(1500));
+        assert_eq!(rect.checked_area(), Some(rect.area() as u64));
+        // Would overflow u32.
+        let big = Rectangle { x: 0, y: 0, width: 100_000, height: 100_000 };
+        assert_eq!(big.checked_area(), Some(10_000_000_000));
+    }
+
+    #[test]
+    fn test_scale() {
+        let rect = Rectangle { x: 1, y: 1, width: 2, height: 3 }.scale(2);
+        assert_eq!((rect.x, rect.y, rect.width, rect.height), (1, 1, 4, 6));
+        // Large factor saturates.
+        let rect = Rectangle { x: 0, y: 0, width: 2, height: 3 }.scale(u32::MAX / 2);
+        assert_eq!((rect.width, rect.height), (u32::MAX - 1, u32::MAX));
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(Rectangle { x: 0, y: 0, width: 4, height: 2 }.aspect_ratio(), 2.0);
+        assert_eq!(Rectangle { x: 0, y: 0, width: 4, height: 0 }.aspect_ratio(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let rect = Rectangle { x: 10, y: 10, width: 5, height: 5 };
+        assert!(rect.contains_point(12, 12));
+        // Edges are inclusive.
+        assert!(rect.contains_point(10, 15));
+        assert!(rect.contains_point(15, 10));
+        assert!(!rect.contains_point(16, 12));
+        assert!(!rect.contains_point(9, 12));
+    }
+
+    #[test]
+    fn test_overlap_area() {
+        let a = Rectangle { x: 0, y: 0, width: 4, height: 4 };
+        let b = Rectangle { x: 2, y: 1, width: 4, height: 4 };
+        assert_eq!(a.overlap_area(&b), 2 * 3);
+        assert_eq!(b.overlap_area(&a), 2 * 3);
+        // Sharing an edge only.
+        let c = Rectangle { x: 4, y: 0, width: 2, height: 2 };
+        assert_eq!(a.overlap_area(&c), 0);
+        // Disjoint.
+        let d = Rectangle { x: 10, y: 10, width: 2, height: 2 };
+        assert_eq!(a.overlap_area(&d), 0);
+        // Overlap larger than u32::MAX is capped.
+        let big = Rectangle { x: 0, y: 0, width: 70_000, height: 70_000 };
+        assert_eq!(big.overlap_area(&big), u32::MAX);
+        let e = Rectangle { x: 10_000, y: 10_000, width: 70_000, height: 70_000 };
+        assert_eq!(big.overlap_area(&e), 60_000 * 60_000);
+    }
+}